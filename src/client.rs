@@ -0,0 +1,232 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{Response, StatusCode};
+use tracing::{debug, warn};
+
+use crate::{OdosError, Result};
+
+/// Configuration for the underlying HTTP client, including the retry/backoff
+/// policy applied to transient and rate-limited failures.
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Request timeout applied to every call.
+    pub timeout: Duration,
+    /// Base delay used to compute exponential backoff (`base * 2^attempt`).
+    pub base_delay: Duration,
+    /// Upper bound on any computed backoff delay, including the jittered one.
+    pub max_delay: Duration,
+    /// Maximum number of retries before giving up.
+    pub max_retries: u32,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(30),
+            max_retries: 5,
+        }
+    }
+}
+
+/// Thin wrapper around [`reqwest::Client`] that centralizes retry/backoff
+/// behavior for calls against the Odos API.
+#[derive(Debug, Clone)]
+pub struct OdosHttpClient {
+    inner: reqwest::Client,
+    config: ClientConfig,
+}
+
+impl OdosHttpClient {
+    pub fn new() -> Result<Self> {
+        Self::with_config(ClientConfig::default())
+    }
+
+    pub fn with_config(config: ClientConfig) -> Result<Self> {
+        let inner = reqwest::Client::builder().timeout(config.timeout).build()?;
+        Ok(Self { inner, config })
+    }
+
+    /// Get the client configuration.
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// The underlying [`reqwest::Client`], for building requests.
+    pub fn inner(&self) -> &reqwest::Client {
+        &self.inner
+    }
+
+    /// Execute a request built by `build`, retrying on transient failures.
+    ///
+    /// HTTP 429 and 503 responses are treated as rate limiting: the
+    /// `Retry-After` header is honored when present (both delta-seconds and
+    /// HTTP-date forms), otherwise the delay falls back to exponential
+    /// backoff (`min(base * 2^attempt, max_delay)`) with full jitter, sleeping
+    /// a uniformly random duration between zero and that cap so concurrent
+    /// clients don't retry in lockstep. Exhausting retries while the last
+    /// response was a 429/503 surfaces [`OdosError::RateLimited`] instead of
+    /// [`OdosError::RetryExhausted`] so callers can distinguish the two.
+    pub async fn execute_with_retry<F>(&self, build: F) -> Result<Response>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let max_retries = self.config.max_retries;
+        let mut last_was_rate_limited = false;
+        let mut last_message = String::new();
+
+        for attempt in 0..=max_retries {
+            let result = build().send().await;
+
+            match result {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() || !Self::is_retryable_status(status) {
+                        return Ok(response);
+                    }
+
+                    last_was_rate_limited = Self::is_rate_limit_status(status);
+                    last_message = format!("HTTP {status}");
+
+                    if attempt == max_retries {
+                        break;
+                    }
+
+                    let delay = Self::retry_after(&response)
+                        .unwrap_or_else(|| self.backoff_with_jitter(attempt));
+                    debug!(attempt, ?delay, %status, "retrying Odos request");
+                    tokio::time::sleep(delay).await;
+                }
+                Err(err) => {
+                    last_was_rate_limited = false;
+                    last_message = err.to_string();
+
+                    if attempt == max_retries {
+                        break;
+                    }
+
+                    let delay = self.backoff_with_jitter(attempt);
+                    warn!(attempt, ?delay, error = %err, "retrying Odos request after transport error");
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+
+        if last_was_rate_limited {
+            Err(OdosError::rate_limited(max_retries + 1, last_message))
+        } else {
+            Err(OdosError::retry_exhausted(max_retries + 1, last_message))
+        }
+    }
+
+    fn is_rate_limit_status(status: StatusCode) -> bool {
+        matches!(status, StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE)
+    }
+
+    fn is_retryable_status(status: StatusCode) -> bool {
+        Self::is_rate_limit_status(status) || status.is_server_error()
+    }
+
+    /// Parse the `Retry-After` header off a response, if present.
+    fn retry_after(response: &Response) -> Option<Duration> {
+        let header = response.headers().get(reqwest::header::RETRY_AFTER)?;
+        let value = header.to_str().ok()?;
+
+        if let Ok(seconds) = value.trim().parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let target = httpdate::parse_http_date(value.trim()).ok()?;
+        Some(
+            target
+                .duration_since(std::time::SystemTime::now())
+                .unwrap_or(Duration::ZERO),
+        )
+    }
+
+    /// `min(base * 2^attempt, max_delay)`, with full jitter (a uniformly
+    /// random duration between zero and that cap).
+    fn backoff_with_jitter(&self, attempt: u32) -> Duration {
+        let cap = self
+            .config
+            .base_delay
+            .saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX))
+            .min(self.config.max_delay);
+
+        let jitter_ms = rand::thread_rng().gen_range(0..=cap.as_millis() as u64);
+        Duration::from_millis(jitter_ms)
+    }
+}
+
+impl Default for OdosHttpClient {
+    fn default() -> Self {
+        Self::new().expect("Failed to create default OdosHttpClient")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client_with(base_delay: Duration, max_delay: Duration) -> OdosHttpClient {
+        OdosHttpClient::with_config(ClientConfig {
+            base_delay,
+            max_delay,
+            ..ClientConfig::default()
+        })
+        .unwrap()
+    }
+
+    fn response_with(status: u16, retry_after: Option<&str>) -> Response {
+        let mut builder = http::Response::builder().status(status);
+        if let Some(value) = retry_after {
+            builder = builder.header(reqwest::header::RETRY_AFTER, value);
+        }
+        builder.body(Vec::new()).unwrap().into()
+    }
+
+    #[test]
+    fn retry_after_parses_delta_seconds() {
+        let response = response_with(429, Some("2"));
+        assert_eq!(
+            OdosHttpClient::retry_after(&response),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn retry_after_parses_http_date_in_the_past_as_zero() {
+        let response = response_with(503, Some("Thu, 01 Jan 1970 00:00:00 GMT"));
+        assert_eq!(OdosHttpClient::retry_after(&response), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_missing_header_returns_none() {
+        let response = response_with(429, None);
+        assert_eq!(OdosHttpClient::retry_after(&response), None);
+    }
+
+    #[test]
+    fn retry_after_unparseable_header_returns_none() {
+        let response = response_with(429, Some("not-a-delay"));
+        assert_eq!(OdosHttpClient::retry_after(&response), None);
+    }
+
+    #[test]
+    fn backoff_with_jitter_stays_within_cap() {
+        let client = client_with(Duration::from_millis(100), Duration::from_secs(1));
+        for attempt in 0..10 {
+            assert!(client.backoff_with_jitter(attempt) <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn backoff_with_jitter_saturates_instead_of_overflowing() {
+        let client = client_with(Duration::from_millis(100), Duration::from_millis(500));
+        // `2^attempt` overflows `u32` for any attempt of 32 or more; without the
+        // `checked_shl`/`saturating_mul` guards this would panic instead of capping.
+        assert!(client.backoff_with_jitter(u32::MAX) <= Duration::from_millis(500));
+    }
+}
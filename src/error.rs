@@ -0,0 +1,77 @@
+use thiserror::Error;
+
+/// Errors produced by the Odos client.
+#[derive(Debug, Error)]
+pub enum OdosError {
+    /// The underlying HTTP transport failed.
+    #[error("HTTP transport error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// A response body could not be decoded as JSON.
+    #[error("JSON decode error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Returned calldata could not be hex-decoded.
+    #[error("hex decode error: {0}")]
+    Hex(#[from] alloy_primitives::hex::FromHexError),
+
+    /// The `/sor/quote/v2` endpoint rejected the request.
+    #[error("quote request error: {0}")]
+    QuoteRequest(String),
+
+    /// The `/sor/assemble` endpoint rejected the request.
+    #[error("transaction assembly error: {0}")]
+    TransactionAssembly(String),
+
+    /// An `/info/*` endpoint (chains, router address, token metadata)
+    /// rejected the request or returned unusable data.
+    #[error("info request error: {0}")]
+    InfoRequest(String),
+
+    /// Retries against the Odos API were exhausted because the server kept
+    /// responding with HTTP 429/503. Distinguished from other exhaustion so
+    /// callers can back off at a higher level instead of treating it as a
+    /// generic failure.
+    #[error("rate limited after {attempts} attempt(s): {message}")]
+    RateLimited { attempts: u32, message: String },
+
+    /// Retries were exhausted for a reason other than rate limiting.
+    #[error("retry exhausted after {attempts} attempt(s): {message}")]
+    RetryExhausted { attempts: u32, message: String },
+}
+
+impl OdosError {
+    /// Build a [`OdosError::QuoteRequest`] from a message.
+    pub fn quote_request_error(message: impl Into<String>) -> Self {
+        Self::QuoteRequest(message.into())
+    }
+
+    /// Build a [`OdosError::TransactionAssembly`] from a message.
+    pub fn transaction_assembly_error(message: impl Into<String>) -> Self {
+        Self::TransactionAssembly(message.into())
+    }
+
+    /// Build a [`OdosError::InfoRequest`] from a message.
+    pub fn info_request_error(message: impl Into<String>) -> Self {
+        Self::InfoRequest(message.into())
+    }
+
+    /// Build a [`OdosError::RateLimited`] from the number of attempts made and a message.
+    pub fn rate_limited(attempts: u32, message: impl Into<String>) -> Self {
+        Self::RateLimited {
+            attempts,
+            message: message.into(),
+        }
+    }
+
+    /// Build a [`OdosError::RetryExhausted`] from the number of attempts made and a message.
+    pub fn retry_exhausted(attempts: u32, message: impl Into<String>) -> Self {
+        Self::RetryExhausted {
+            attempts,
+            message: message.into(),
+        }
+    }
+}
+
+/// Convenience alias used throughout the crate.
+pub type Result<T> = std::result::Result<T, OdosError>;
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use alloy_primitives::Address;
+use serde::Deserialize;
+use tracing::{debug, instrument};
+
+use crate::{ClientConfig, OdosError, OdosHttpClient, Result};
+
+const CHAINS_URL: &str = "https://api.odos.xyz/info/chains";
+const ROUTER_URL: &str = "https://api.odos.xyz/info/router/v2";
+const TOKENS_URL: &str = "https://api.odos.xyz/info/tokens/v2";
+
+/// The `/info/router/v2/{chainId}` URL for `chain_id`.
+fn router_url(chain_id: u64) -> String {
+    format!("{ROUTER_URL}/{chain_id}")
+}
+
+/// A chain supported by Odos, as returned by the `/info/chains` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainInfo {
+    pub chain_id: u64,
+    pub chain_name: String,
+}
+
+/// Metadata for a single token, as returned by the `/info/tokens/v2` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenInfo {
+    pub address: Address,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+}
+
+/// Client for the Odos info endpoints: supported chains, the per-chain router
+/// contract address, and token metadata.
+///
+/// Built on [`OdosHttpClient`], like [`crate::OdosSorV2`], so both use the same
+/// retry/backoff logic. Each client owns its own [`OdosHttpClient`], configured
+/// by the [`ClientConfig`] it was built with.
+#[derive(Debug, Clone)]
+pub struct OdosInfo {
+    client: OdosHttpClient,
+}
+
+impl OdosInfo {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            client: OdosHttpClient::new()?,
+        })
+    }
+
+    pub fn with_config(config: ClientConfig) -> Result<Self> {
+        Ok(Self {
+            client: OdosHttpClient::with_config(config)?,
+        })
+    }
+
+    /// All chains currently supported by Odos.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn supported_chains(&self) -> Result<Vec<ChainInfo>> {
+        let response = self
+            .client
+            .execute_with_retry(|| {
+                self.client
+                    .inner()
+                    .get(CHAINS_URL)
+                    .header("accept", "application/json")
+            })
+            .await?;
+
+        debug!(response = ?response);
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(OdosError::info_request_error(format!(
+                "API error (status: {status}) fetching supported chains"
+            )));
+        }
+
+        #[derive(Deserialize)]
+        struct ChainsResponse {
+            chains: Vec<ChainInfo>,
+        }
+
+        let parsed: ChainsResponse = response.json().await?;
+        Ok(parsed.chains)
+    }
+
+    /// The Odos router contract address deployed on `chain_id`.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn router_address(&self, chain_id: u64) -> Result<Address> {
+        let response = self
+            .client
+            .execute_with_retry(|| {
+                self.client
+                    .inner()
+                    .get(router_url(chain_id))
+                    .header("accept", "application/json")
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(OdosError::info_request_error(format!(
+                "API error (status: {status}) fetching router address for chain {chain_id}"
+            )));
+        }
+
+        #[derive(Deserialize)]
+        struct RouterResponse {
+            address: Address,
+        }
+
+        let parsed: RouterResponse = response.json().await?;
+        Ok(parsed.address)
+    }
+
+    /// Metadata (decimals, symbol, name) for `address` on `chain_id`.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn token_info(&self, chain_id: u64, address: Address) -> Result<TokenInfo> {
+        let tokens = self.token_list(chain_id).await?;
+        tokens.get(&address).cloned().ok_or_else(|| {
+            OdosError::info_request_error(format!(
+                "token {address} is not listed by Odos on chain {chain_id}"
+            ))
+        })
+    }
+
+    /// The full token list for `chain_id`, keyed by address.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn token_list(&self, chain_id: u64) -> Result<HashMap<Address, TokenInfo>> {
+        let response = self
+            .client
+            .execute_with_retry(|| {
+                self.client
+                    .inner()
+                    .get(format!("{TOKENS_URL}/{chain_id}"))
+                    .header("accept", "application/json")
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(OdosError::info_request_error(format!(
+                "API error (status: {status}) fetching token list for chain {chain_id}"
+            )));
+        }
+
+        #[derive(Deserialize)]
+        struct TokenListResponse {
+            #[serde(rename = "tokenMap")]
+            token_map: HashMap<Address, TokenInfo>,
+        }
+
+        let parsed: TokenListResponse = response.json().await?;
+        Ok(parsed.token_map)
+    }
+}
+
+impl Default for OdosInfo {
+    fn default() -> Self {
+        Self::new().expect("Failed to create default OdosInfo client")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn router_url_puts_chain_id_in_the_path() {
+        assert_eq!(
+            router_url(1),
+            "https://api.odos.xyz/info/router/v2/1"
+        );
+    }
+
+    #[test]
+    fn chain_info_deserializes_from_chains_response() {
+        let chain: ChainInfo =
+            serde_json::from_value(json!({"chainId": 1, "chainName": "Ethereum"})).unwrap();
+        assert_eq!(chain.chain_id, 1);
+        assert_eq!(chain.chain_name, "Ethereum");
+    }
+
+    #[test]
+    fn token_info_deserializes_from_token_list_response() {
+        let token: TokenInfo = serde_json::from_value(json!({
+            "address": "0x0000000000000000000000000000000000000001",
+            "symbol": "TOK",
+            "name": "Token",
+            "decimals": 18
+        }))
+        .unwrap();
+        assert_eq!(token.symbol, "TOK");
+        assert_eq!(token.decimals, 18);
+    }
+}
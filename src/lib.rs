@@ -0,0 +1,28 @@
+//! A Rust client for the [Odos](https://odos.xyz) smart order routing API.
+
+mod client;
+mod error;
+mod info;
+mod sor;
+mod types;
+
+pub use client::{ClientConfig, OdosHttpClient};
+pub use error::{OdosError, Result};
+pub use info::{ChainInfo, OdosInfo, TokenInfo};
+pub use sor::{OdosSorV2, QuoteStreamOptions, SimulationReport};
+pub use types::{
+    AssembleRequest, AssemblyResponse, InputTokenAmount, OutputTokenProportion, QuoteRequest,
+    QuoteRequestBuilder, SingleQuoteResponse, SwapContext, TransactionData,
+};
+
+/// Base URL for the Odos Assemble API.
+pub const ASSEMBLE_URL: &str = "https://api.odos.xyz/sor/assemble";
+
+/// Parse a decimal wei amount returned by the Odos API into a [`alloy_primitives::U256`].
+///
+/// The Odos API returns native-value fields (e.g. `value` on an assembled
+/// transaction) as decimal strings; this treats a missing or malformed value
+/// as zero rather than failing the whole response.
+pub fn parse_value(value: &str) -> alloy_primitives::U256 {
+    value.parse().unwrap_or_default()
+}
@@ -1,19 +1,68 @@
+use std::time::Duration;
+
+use alloy_eips::BlockNumberOrTag;
 use alloy_network::TransactionBuilder;
 use alloy_primitives::{hex, Address};
+use alloy_provider::Provider;
 use alloy_rpc_types::TransactionRequest;
+use async_stream::stream;
+use futures_core::Stream;
 use reqwest::Response;
 use serde_json::Value;
-use tracing::{debug, info, instrument};
+use tracing::{debug, info, instrument, warn};
 
 use crate::{
     parse_value, AssembleRequest, AssemblyResponse, ClientConfig, OdosError, OdosHttpClient,
-    Result, SwapContext, ASSEMBLE_URL,
+    OdosInfo, Result, SwapContext, ASSEMBLE_URL,
 };
 
 use super::TransactionData;
 
 use crate::{QuoteRequest, SingleQuoteResponse};
 
+/// Outcome of simulating an assembled transaction via the Odos Assemble API.
+///
+/// Returned by [`OdosSorV2::simulate_tx`] when a quote is assembled with
+/// `simulate: true`, letting callers dry-run a swap before broadcasting it.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationReport {
+    /// Whether the simulated transaction executed successfully.
+    pub is_success: bool,
+    /// Simulated output amounts, one entry per output token, in raw units.
+    #[serde(default)]
+    pub amounts_out: Vec<String>,
+    /// Gas used by the simulated transaction.
+    #[serde(default)]
+    pub gas_estimate: f64,
+    /// Decoded revert reason, populated when `is_success` is `false`.
+    #[serde(default)]
+    pub revert_reason: Option<String>,
+}
+
+/// Options controlling [`OdosSorV2::quote_stream`].
+#[derive(Debug, Clone)]
+pub struct QuoteStreamOptions {
+    /// How often to re-quote.
+    pub poll_interval: Duration,
+    /// Minimum relative change in any of the quote's output amounts (e.g.
+    /// `0.001` for 0.1%) required before a re-quote is yielded to the caller.
+    pub relative_threshold: f64,
+    /// How many consecutive quote errors to tolerate before ending the
+    /// stream.
+    pub max_consecutive_failures: u32,
+}
+
+impl Default for QuoteStreamOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(5),
+            relative_threshold: 0.001,
+            max_consecutive_failures: 3,
+        }
+    }
+}
+
 /// The Odos Smart Order Routing V2 API client
 #[derive(Debug, Clone)]
 pub struct OdosSorV2 {
@@ -126,10 +175,73 @@ impl OdosSorV2 {
         Ok(transaction)
     }
 
+    /// Simulate a swap from a quote using the Odos Assemble API, without
+    /// producing calldata meant for broadcast.
+    ///
+    /// Sends `simulate: true` so the Odos API executes the assembled
+    /// transaction against a fork and reports back whether it succeeded, the
+    /// resulting output amounts, the gas used, and (when it reverted) a
+    /// decoded revert reason. Use this to dry-run a swap before constructing
+    /// a transaction to sign and broadcast.
+    #[instrument(skip(self), ret(Debug))]
+    pub async fn simulate_tx(
+        &self,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: &str,
+    ) -> Result<SimulationReport> {
+        let assemble_request = AssembleRequest {
+            user_addr: signer_address.to_string(),
+            path_id: path_id.to_string(),
+            simulate: true,
+            receiver: Some(output_recipient),
+        };
+
+        let response = self.get_assemble_response(assemble_request).await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to get error message".to_string());
+
+            return Err(OdosError::transaction_assembly_error(format!(
+                "API error (status: {status}): {error}"
+            )));
+        }
+
+        let value: Value = response.json().await?;
+
+        let simulation = value.get("simulation").cloned().ok_or_else(|| {
+            OdosError::transaction_assembly_error(
+                "Assemble response missing simulation block (was `simulate: true` honored?)"
+                    .to_string(),
+            )
+        })?;
+
+        let report: SimulationReport = serde_json::from_value(simulation)?;
+
+        Ok(report)
+    }
+
     /// Build a base transaction from a swap using the Odos Assemble API,
     /// leaving gas parameters to be set by the caller.
+    ///
+    /// `swap` must carry a router address (built via [`SwapContext::new`]).
+    /// Use [`Self::build_base_transaction_for_chain`] for a [`SwapContext`]
+    /// built with [`SwapContext::new_for_chain`].
     #[instrument(skip(self), ret(Debug))]
     pub async fn build_base_transaction(&self, swap: &SwapContext) -> Result<TransactionRequest> {
+        let router_address = swap.router_address().ok_or_else(|| {
+            OdosError::transaction_assembly_error(
+                "swap has no router address; build it with SwapContext::new, or use \
+                 build_base_transaction_for_chain for a SwapContext built with new_for_chain",
+            )
+        })?;
+
+        log_assembling(swap);
+
         let TransactionData { data, value, .. } = self
             .assemble_tx_data(
                 swap.signer_address(),
@@ -143,9 +255,200 @@ impl OdosSorV2 {
         Ok(TransactionRequest::default()
             .with_input(hex::decode(&data)?)
             .with_value(parse_value(&value))
-            .with_to(swap.router_address())
+            .with_to(router_address)
             .with_from(swap.signer_address()))
     }
+
+    /// Build a base transaction the same way as [`Self::build_base_transaction`],
+    /// but resolve the router address from `chain_id` via `info` instead of
+    /// requiring `swap` to already carry the per-chain deployment address.
+    ///
+    /// Build `swap` with [`SwapContext::new_for_chain`] so callers don't have
+    /// to hardcode a router address just to construct one.
+    #[instrument(skip(self, info), ret(Debug))]
+    pub async fn build_base_transaction_for_chain(
+        &self,
+        swap: &SwapContext,
+        info: &OdosInfo,
+        chain_id: u64,
+    ) -> Result<TransactionRequest> {
+        let router_address = info.router_address(chain_id).await?;
+
+        log_assembling(swap);
+
+        let TransactionData { data, value, .. } = self
+            .assemble_tx_data(
+                swap.signer_address(),
+                swap.output_recipient(),
+                swap.path_id(),
+            )
+            .await?;
+
+        info!(value = %value, %router_address, "Building base transaction for chain");
+
+        Ok(TransactionRequest::default()
+            .with_input(hex::decode(&data)?)
+            .with_value(parse_value(&value))
+            .with_to(router_address)
+            .with_from(swap.signer_address()))
+    }
+
+    /// Build a fully populated, signable [`TransactionRequest`] for `swap`.
+    ///
+    /// The gas limit is the assemble response's `gasEstimate`, scaled by
+    /// `gas_safety_multiplier` (e.g. `1.2` for a 20% buffer). `max_fee_per_gas`
+    /// and `max_priority_fee_per_gas` are filled by querying `provider` for a
+    /// recent fee history and a priority-fee estimate, so the result is ready
+    /// to sign without further caller input. Use [`Self::build_base_transaction`]
+    /// instead if you want to manage gas parameters yourself.
+    #[instrument(skip(self, provider), ret(Debug))]
+    pub async fn build_ready_transaction<P>(
+        &self,
+        swap: &SwapContext,
+        provider: &P,
+        gas_safety_multiplier: f64,
+    ) -> Result<TransactionRequest>
+    where
+        P: Provider + Sync,
+    {
+        let router_address = swap.router_address().ok_or_else(|| {
+            OdosError::transaction_assembly_error(
+                "swap has no router address; build it with SwapContext::new, or use \
+                 build_base_transaction_for_chain for a SwapContext built with new_for_chain",
+            )
+        })?;
+
+        log_assembling(swap);
+
+        let assemble_request = AssembleRequest {
+            user_addr: swap.signer_address().to_string(),
+            path_id: swap.path_id().to_string(),
+            simulate: false,
+            receiver: Some(swap.output_recipient()),
+        };
+
+        let response = self.get_assemble_response(assemble_request).await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to get error message".to_string());
+
+            return Err(OdosError::transaction_assembly_error(format!(
+                "API error (status: {status}): {error}"
+            )));
+        }
+
+        let value: Value = response.json().await?;
+        let AssemblyResponse {
+            transaction,
+            gas_estimate,
+            ..
+        } = serde_json::from_value(value)?;
+
+        if gas_estimate <= 0.0 {
+            return Err(OdosError::transaction_assembly_error(
+                "assemble response missing a positive gasEstimate",
+            ));
+        }
+
+        let TransactionData {
+            data,
+            value: tx_value,
+            ..
+        } = transaction;
+
+        let gas_limit = compute_gas_limit(gas_estimate, gas_safety_multiplier);
+
+        let priority_fee = provider.get_max_priority_fee_per_gas().await.map_err(|e| {
+            OdosError::transaction_assembly_error(format!(
+                "failed to fetch priority fee estimate: {e}"
+            ))
+        })?;
+
+        let fee_history = provider
+            .get_fee_history(1, BlockNumberOrTag::Latest, &[])
+            .await
+            .map_err(|e| {
+                OdosError::transaction_assembly_error(format!("failed to fetch fee history: {e}"))
+            })?;
+
+        let base_fee = fee_history.base_fee_per_gas.last().copied().unwrap_or_default();
+        // Double the latest base fee so the cap still clears a few blocks of
+        // increase, then add the tip on top, matching common wallet heuristics.
+        let max_fee = base_fee.saturating_mul(2).saturating_add(priority_fee);
+
+        info!(gas_limit, max_fee, priority_fee, "Building ready transaction");
+
+        Ok(TransactionRequest::default()
+            .with_input(hex::decode(&data)?)
+            .with_value(parse_value(&tx_value))
+            .with_to(router_address)
+            .with_from(swap.signer_address())
+            .with_gas_limit(gas_limit)
+            .with_max_fee_per_gas(max_fee)
+            .with_max_priority_fee_per_gas(priority_fee))
+    }
+
+    /// Continuously re-quote `quote_request`, yielding a new item only when
+    /// some output token's amount moves beyond `opts.relative_threshold`
+    /// relative to the last yielded quote (the max relative delta across all
+    /// of the quote's `out_amounts`, so a rebalance/consolidation quote isn't
+    /// silently stuck on only its first output token).
+    ///
+    /// Re-quotes every `opts.poll_interval`. Each yielded
+    /// [`SingleQuoteResponse`] carries the quote's `path_id`, ready to hand
+    /// straight to [`Self::assemble_tx_data`]. Transient quote errors are
+    /// surfaced as `Err` items without ending the stream, up to
+    /// `opts.max_consecutive_failures` consecutive failures, after which the
+    /// stream ends.
+    #[instrument(skip(self, quote_request, opts))]
+    pub fn quote_stream<'a>(
+        &'a self,
+        quote_request: QuoteRequest,
+        opts: QuoteStreamOptions,
+    ) -> impl Stream<Item = Result<SingleQuoteResponse>> + 'a {
+        stream! {
+            let mut last_out_amounts: Option<Vec<f64>> = None;
+            let mut consecutive_failures = 0u32;
+
+            loop {
+                match self.get_swap_quote(&quote_request).await {
+                    Ok(quote) => {
+                        consecutive_failures = 0;
+
+                        let out_amounts: Option<Vec<f64>> = quote
+                            .out_amounts
+                            .iter()
+                            .map(|amount| amount.parse::<f64>().ok())
+                            .collect();
+
+                        if material_change(
+                            last_out_amounts.as_deref(),
+                            out_amounts.as_deref(),
+                            opts.relative_threshold,
+                        ) {
+                            last_out_amounts = out_amounts;
+                            yield Ok(quote);
+                        }
+                    }
+                    Err(err) => {
+                        consecutive_failures += 1;
+                        warn!(consecutive_failures, error = %err, "quote_stream re-quote failed");
+                        let exhausted = consecutive_failures >= opts.max_consecutive_failures;
+                        yield Err(err);
+                        if exhausted {
+                            break;
+                        }
+                    }
+                }
+
+                tokio::time::sleep(opts.poll_interval).await;
+            }
+        }
+    }
 }
 
 impl Default for OdosSorV2 {
@@ -153,3 +456,113 @@ impl Default for OdosSorV2 {
         Self::new().expect("Failed to create default OdosSorV2 client")
     }
 }
+
+/// `(gas_estimate * gas_safety_multiplier).ceil()`, as a gas limit.
+fn compute_gas_limit(gas_estimate: f64, gas_safety_multiplier: f64) -> u64 {
+    (gas_estimate * gas_safety_multiplier).ceil() as u64
+}
+
+/// Log the multi-asset shape of `swap` right before it's assembled.
+fn log_assembling(swap: &SwapContext) {
+    debug!(
+        multi_asset = swap.is_multi_asset(),
+        input_tokens = swap.input_tokens().len(),
+        output_tokens = swap.output_tokens().len(),
+        "assembling swap"
+    );
+}
+
+/// Whether `current` differs from `last` by at least `threshold` in any
+/// output token's amount (the max relative delta across all outputs).
+///
+/// Returns `true` — a material change — if there's no `last` to compare
+/// against yet, if the output count differs between quotes, or if `current`
+/// is `None` (an amount failed to parse), since there's nothing safe to
+/// compare in those cases.
+fn material_change(last: Option<&[f64]>, current: Option<&[f64]>, threshold: f64) -> bool {
+    match (last, current) {
+        (Some(last), Some(current)) if last.len() == current.len() => {
+            last.iter().zip(current).any(|(last, current)| {
+                if *last == 0.0 {
+                    *current != 0.0
+                } else {
+                    ((current - last) / last).abs() >= threshold
+                }
+            })
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn simulation_report_deserializes_success_case() {
+        let report: SimulationReport = serde_json::from_value(json!({
+            "isSuccess": true,
+            "amountsOut": ["123456789"],
+            "gasEstimate": 150000.5,
+        }))
+        .unwrap();
+        assert!(report.is_success);
+        assert_eq!(report.amounts_out, vec!["123456789".to_string()]);
+        assert_eq!(report.gas_estimate, 150000.5);
+        assert_eq!(report.revert_reason, None);
+    }
+
+    #[test]
+    fn simulation_report_deserializes_revert_case() {
+        let report: SimulationReport = serde_json::from_value(json!({
+            "isSuccess": false,
+            "revertReason": "INSUFFICIENT_OUTPUT_AMOUNT",
+        }))
+        .unwrap();
+        assert!(!report.is_success);
+        assert!(report.amounts_out.is_empty());
+        assert_eq!(
+            report.revert_reason.as_deref(),
+            Some("INSUFFICIENT_OUTPUT_AMOUNT")
+        );
+    }
+
+    #[test]
+    fn compute_gas_limit_applies_safety_multiplier_and_rounds_up() {
+        assert_eq!(compute_gas_limit(100_000.0, 1.2), 120_000);
+        assert_eq!(compute_gas_limit(100_000.1, 1.0), 100_001);
+    }
+
+    #[test]
+    fn material_change_is_true_with_no_prior_quote() {
+        assert!(material_change(None, Some(&[1.0]), 0.01));
+    }
+
+    #[test]
+    fn material_change_is_true_when_output_count_changes() {
+        assert!(material_change(Some(&[1.0]), Some(&[1.0, 2.0]), 0.01));
+    }
+
+    #[test]
+    fn material_change_is_true_when_amounts_fail_to_parse() {
+        assert!(material_change(Some(&[1.0]), None, 0.01));
+    }
+
+    #[test]
+    fn material_change_detects_a_move_in_a_non_first_output() {
+        // First output unchanged, second output moves by 10% — should still
+        // be reported as a material change.
+        assert!(material_change(Some(&[1.0, 1.0]), Some(&[1.0, 1.1]), 0.05));
+    }
+
+    #[test]
+    fn material_change_is_false_within_threshold_on_every_output() {
+        assert!(!material_change(
+            Some(&[1.0, 2.0]),
+            Some(&[1.0005, 2.001]),
+            0.01
+        ));
+    }
+}
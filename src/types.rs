@@ -0,0 +1,365 @@
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::{OdosError, Result};
+
+/// A single input token and the amount (in raw units) to spend from it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputTokenAmount {
+    pub token_address: Address,
+    pub amount: String,
+}
+
+/// A single output token and the target proportion of the swap's value it
+/// should receive. Proportions across a request's output tokens must sum to
+/// `1.0`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputTokenProportion {
+    pub token_address: Address,
+    pub proportion: f64,
+}
+
+/// Request body for `/sor/quote/v2`.
+///
+/// Supports the common single-input/single-output swap as well as
+/// multi-input/multi-output rebalance or consolidation swaps, via
+/// `input_tokens`/`output_tokens` arrays. Build one with [`QuoteRequest::builder`]
+/// rather than constructing it directly, so output proportions are validated.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteRequest {
+    pub chain_id: u64,
+    pub input_tokens: Vec<InputTokenAmount>,
+    pub output_tokens: Vec<OutputTokenProportion>,
+    pub user_addr: String,
+    pub slippage_limit_percent: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referral_code: Option<u64>,
+}
+
+impl QuoteRequest {
+    /// Start building a [`QuoteRequest`] for `chain_id` and `user_addr`.
+    pub fn builder(chain_id: u64, user_addr: impl Into<String>) -> QuoteRequestBuilder {
+        QuoteRequestBuilder::new(chain_id, user_addr)
+    }
+
+    /// Build a [`QuoteRequest`] for the common single-input/single-output
+    /// swap, going through [`QuoteRequestBuilder`] under the hood.
+    ///
+    /// Equivalent to
+    /// `QuoteRequest::builder(chain_id, user_addr).input(input_token, input_amount).output(output_token, 1.0).build()`.
+    pub fn single(
+        chain_id: u64,
+        input_token: Address,
+        input_amount: impl Into<String>,
+        output_token: Address,
+        user_addr: impl Into<String>,
+    ) -> Result<Self> {
+        Self::builder(chain_id, user_addr)
+            .input(input_token, input_amount)
+            .output(output_token, 1.0)
+            .build()
+    }
+}
+
+/// Builder for [`QuoteRequest`].
+///
+/// Validates, at [`Self::build`] time, that there is at least one input and
+/// one output token and that the output proportions sum to `1.0` (within
+/// floating-point tolerance) — catching a malformed rebalance/consolidation
+/// request before it reaches the Odos API.
+#[derive(Debug, Clone)]
+pub struct QuoteRequestBuilder {
+    chain_id: u64,
+    user_addr: String,
+    input_tokens: Vec<InputTokenAmount>,
+    output_tokens: Vec<OutputTokenProportion>,
+    slippage_limit_percent: f64,
+    referral_code: Option<u64>,
+}
+
+/// Output proportions must sum to `1.0` within this tolerance.
+const PROPORTION_SUM_TOLERANCE: f64 = 1e-6;
+
+impl QuoteRequestBuilder {
+    pub fn new(chain_id: u64, user_addr: impl Into<String>) -> Self {
+        Self {
+            chain_id,
+            user_addr: user_addr.into(),
+            input_tokens: Vec::new(),
+            output_tokens: Vec::new(),
+            slippage_limit_percent: 0.3,
+            referral_code: None,
+        }
+    }
+
+    /// Add an input token and the amount (in raw units) to spend from it.
+    pub fn input(mut self, token_address: Address, amount: impl Into<String>) -> Self {
+        self.input_tokens.push(InputTokenAmount {
+            token_address,
+            amount: amount.into(),
+        });
+        self
+    }
+
+    /// Add an output token and its target proportion of the swap's value.
+    pub fn output(mut self, token_address: Address, proportion: f64) -> Self {
+        self.output_tokens.push(OutputTokenProportion {
+            token_address,
+            proportion,
+        });
+        self
+    }
+
+    pub fn slippage_limit_percent(mut self, slippage_limit_percent: f64) -> Self {
+        self.slippage_limit_percent = slippage_limit_percent;
+        self
+    }
+
+    pub fn referral_code(mut self, referral_code: u64) -> Self {
+        self.referral_code = Some(referral_code);
+        self
+    }
+
+    /// Validate and build the [`QuoteRequest`].
+    pub fn build(self) -> Result<QuoteRequest> {
+        if self.input_tokens.is_empty() {
+            return Err(OdosError::quote_request_error(
+                "quote request must have at least one input token",
+            ));
+        }
+        if self.output_tokens.is_empty() {
+            return Err(OdosError::quote_request_error(
+                "quote request must have at least one output token",
+            ));
+        }
+
+        let proportion_sum: f64 = self.output_tokens.iter().map(|t| t.proportion).sum();
+        if (proportion_sum - 1.0).abs() > PROPORTION_SUM_TOLERANCE {
+            return Err(OdosError::quote_request_error(format!(
+                "output token proportions must sum to 1.0, got {proportion_sum}"
+            )));
+        }
+
+        Ok(QuoteRequest {
+            chain_id: self.chain_id,
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+            user_addr: self.user_addr,
+            slippage_limit_percent: self.slippage_limit_percent,
+            referral_code: self.referral_code,
+        })
+    }
+}
+
+/// Response body for `/sor/quote/v2`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleQuoteResponse {
+    pub path_id: String,
+    #[serde(default)]
+    pub in_amounts: Vec<String>,
+    #[serde(default)]
+    pub out_amounts: Vec<String>,
+    #[serde(default)]
+    pub gas_estimate: f64,
+}
+
+/// Request body for `/sor/assemble`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssembleRequest {
+    pub user_addr: String,
+    pub path_id: String,
+    pub simulate: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<Address>,
+}
+
+/// A fully assembled, unsigned transaction, as returned by `/sor/assemble`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionData {
+    pub to: Address,
+    pub from: Address,
+    pub data: String,
+    pub value: String,
+    #[serde(default)]
+    pub nonce: u64,
+    #[serde(default)]
+    pub gas: u64,
+}
+
+/// Response body for `/sor/assemble`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssemblyResponse {
+    pub transaction: TransactionData,
+    #[serde(default)]
+    pub block_number: u64,
+    #[serde(default)]
+    pub gas_estimate: f64,
+}
+
+/// Everything needed to assemble and build a transaction for a previously
+/// obtained Odos quote.
+///
+/// Can also carry the input/output tokens from the originating
+/// [`QuoteRequest`] (see [`Self::with_assets`]) for introspection. Assembly
+/// uses only the `path_id`, so a multi-input/multi-output (rebalance or
+/// consolidation) swap is assembled and turned into a transaction the same
+/// way as a single-asset one.
+#[derive(Debug, Clone)]
+pub struct SwapContext {
+    signer_address: Address,
+    output_recipient: Address,
+    router_address: Option<Address>,
+    path_id: String,
+    input_tokens: Vec<InputTokenAmount>,
+    output_tokens: Vec<OutputTokenProportion>,
+}
+
+impl SwapContext {
+    /// Build a [`SwapContext`] for a single-input/single-output swap, with an
+    /// already-known router address.
+    pub fn new(
+        signer_address: Address,
+        output_recipient: Address,
+        router_address: Address,
+        path_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            signer_address,
+            output_recipient,
+            router_address: Some(router_address),
+            path_id: path_id.into(),
+            input_tokens: Vec::new(),
+            output_tokens: Vec::new(),
+        }
+    }
+
+    /// Build a [`SwapContext`] without a router address, for use with
+    /// [`crate::OdosSorV2::build_base_transaction_for_chain`], which resolves
+    /// the router address from a chain ID via [`crate::OdosInfo`] instead of
+    /// requiring the caller to supply or hardcode one up front.
+    pub fn new_for_chain(
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            signer_address,
+            output_recipient,
+            router_address: None,
+            path_id: path_id.into(),
+            input_tokens: Vec::new(),
+            output_tokens: Vec::new(),
+        }
+    }
+
+    /// Attach the input/output tokens from the [`QuoteRequest`] that produced
+    /// this context's `path_id`, so callers can introspect a multi-asset swap
+    /// without re-fetching the quote.
+    pub fn with_assets(
+        mut self,
+        input_tokens: Vec<InputTokenAmount>,
+        output_tokens: Vec<OutputTokenProportion>,
+    ) -> Self {
+        self.input_tokens = input_tokens;
+        self.output_tokens = output_tokens;
+        self
+    }
+
+    pub fn signer_address(&self) -> Address {
+        self.signer_address
+    }
+
+    pub fn output_recipient(&self) -> Address {
+        self.output_recipient
+    }
+
+    /// The router address, if one was supplied via [`Self::new`]. Contexts
+    /// built with [`Self::new_for_chain`] have none, since it's resolved
+    /// separately from a chain ID.
+    pub fn router_address(&self) -> Option<Address> {
+        self.router_address
+    }
+
+    pub fn path_id(&self) -> &str {
+        &self.path_id
+    }
+
+    pub fn input_tokens(&self) -> &[InputTokenAmount] {
+        &self.input_tokens
+    }
+
+    pub fn output_tokens(&self) -> &[OutputTokenProportion] {
+        &self.output_tokens
+    }
+
+    /// Whether this context covers a multi-input and/or multi-output swap.
+    pub fn is_multi_asset(&self) -> bool {
+        self.input_tokens.len() > 1 || self.output_tokens.len() > 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+
+    const TOKEN_A: Address = address!("0000000000000000000000000000000000000001");
+    const TOKEN_B: Address = address!("0000000000000000000000000000000000000002");
+    const TOKEN_C: Address = address!("0000000000000000000000000000000000000003");
+
+    #[test]
+    fn build_rejects_no_inputs() {
+        let err = QuoteRequest::builder(1, "0xabc")
+            .output(TOKEN_B, 1.0)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, OdosError::QuoteRequest(_)));
+    }
+
+    #[test]
+    fn build_rejects_no_outputs() {
+        let err = QuoteRequest::builder(1, "0xabc")
+            .input(TOKEN_A, "1000")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, OdosError::QuoteRequest(_)));
+    }
+
+    #[test]
+    fn build_rejects_proportions_not_summing_to_one() {
+        let err = QuoteRequest::builder(1, "0xabc")
+            .input(TOKEN_A, "1000")
+            .output(TOKEN_B, 0.4)
+            .output(TOKEN_C, 0.4)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, OdosError::QuoteRequest(_)));
+    }
+
+    #[test]
+    fn build_accepts_proportions_within_tolerance() {
+        let request = QuoteRequest::builder(1, "0xabc")
+            .input(TOKEN_A, "1000")
+            .output(TOKEN_B, 0.5)
+            .output(TOKEN_C, 0.5 + 1e-7)
+            .build()
+            .unwrap();
+        assert_eq!(request.output_tokens.len(), 2);
+    }
+
+    #[test]
+    fn single_builds_a_single_asset_request() {
+        let request = QuoteRequest::single(1, TOKEN_A, "1000", TOKEN_B, "0xabc").unwrap();
+        assert_eq!(request.input_tokens.len(), 1);
+        assert_eq!(request.output_tokens.len(), 1);
+        assert_eq!(request.output_tokens[0].proportion, 1.0);
+    }
+}